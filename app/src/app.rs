@@ -1,45 +1,69 @@
 use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
+use std::cell::RefCell;
+use std::rc::Rc;
 use tracker_core::*;
 
 pub use crate::components::*;
+use crate::store::*;
 
 #[component]
 pub fn App(cx: Scope) -> impl IntoView {
     provide_meta_context(cx);
+    let store: Rc<dyn Store> = Rc::new(LocalStorageStore);
 
     view! {
         cx,
         <Title text="Assignment Tracker" />
         <Router>
             <Routes>
-                <Route path="" view=move |cx| view! { cx, <HomePage /> } />
+                <Route path="" view=move |cx| view! { cx, <HomePage store=store.clone() /> } />
             </Routes>
         </Router>
     }
 }
 
 #[component]
-pub fn HomePage(cx: Scope) -> impl IntoView {
-    // TODO: get this from database
-    let course = create_tmp_course().unwrap();
+pub fn HomePage(cx: Scope, store: Rc<dyn Store>) -> impl IntoView {
+    let (courses, store) = load_or_else(store, || vec![create_tmp_course().unwrap()]);
+    let courses = Rc::new(RefCell::new(courses));
 
     view! {
         cx,
         <main class="my-0 mx-auto max-w-3xl">
-            // TOOD: display table for each course
-            <CourseTable course />
+            {courses
+                .borrow()
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, course)| {
+                    let on_save = save_course(store.clone(), courses.clone(), index);
+                    view! { cx, <CourseTable course on_save /> }
+                })
+                .collect::<Vec<_>>()}
         </main>
     }
 }
 
+/// Creates a callback which saves the [Course] at `index` along with all other courses.
+fn save_course(
+    store: Rc<dyn Store>,
+    courses: Rc<RefCell<Vec<Course>>>,
+    index: usize,
+) -> Rc<dyn Fn(&Course) -> anyhow::Result<()>> {
+    Rc::new(move |course: &Course| {
+        courses.borrow_mut()[index] = course.clone();
+        store.save(&courses.borrow())
+    })
+}
+
 fn create_tmp_course() -> anyhow::Result<Course> {
     let mut course = Course::new("Example");
-    course.assignments.push_back(Assignment::new("Assignment 1"))?;
-    course.assignments.push_back(Assignment::new("Assignment 2"))?;
-    course.assignments.push_back(Assignment::new("Assignment 3"))?;
-    course.assignments.push_back(Assignment::new("Exam"))?;
+    course.assignments.push_back(Assignment::new("Assignment 1")?)?;
+    course.assignments.push_back(Assignment::new("Assignment 2")?)?;
+    course.assignments.push_back(Assignment::new("Assignment 3")?)?;
+    course.assignments.push_back(Assignment::new("Exam")?)?;
 
     course.assignments.get_mut(0).unwrap().set_mark(100)?;
     course.assignments.get_mut(0).unwrap().set_weight(25)?;
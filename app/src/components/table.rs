@@ -1,13 +1,66 @@
+use anyhow::anyhow;
 use leptos::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
 use tracker_core::*;
 
+/// Time to wait after the last edit before saving.
+const AUTO_SAVE_DELAY: Duration = Duration::from_millis(500);
+
 #[component]
-pub fn CourseTable(cx: Scope, course: Course) -> impl IntoView {
-    let (assigns, _) = create_signal(cx, course.assignments);
+pub fn CourseTable(
+    cx: Scope,
+    course: Course,
+    on_save: Rc<dyn Fn(&Course) -> anyhow::Result<()>>,
+) -> impl IntoView {
+    let (assigns, set_assigns) = create_signal(cx, course.assignments.clone());
+    let average = create_memo(cx, move |_| assigns.with(Assignments::average_mark));
+    let average_text = move || format_percent(average.get());
+
+    // mark as dirty on every edit, then save once the edits have settled
+    let (dirty, set_dirty) = create_signal(cx, false);
+    let state = Rc::new(RefCell::new(SaveState::default()));
+    let name = course.name.clone();
+    create_effect(cx, move |prev: Option<()>| {
+        assigns.with(|_| ());
+        if prev.is_none() {
+            return;
+        }
+
+        let edit = state.borrow_mut().edit();
+        set_dirty.set(state.borrow().is_dirty());
+
+        let (state, on_save, mut course) = (state.clone(), on_save.clone(), course.clone());
+        set_timeout(
+            move || {
+                if !state.borrow().is_latest(edit) {
+                    return;
+                }
+
+                course.assignments = assigns.get();
+                match on_save(&course) {
+                    Ok(()) => {
+                        state.borrow_mut().saved(edit);
+                        set_dirty.set(state.borrow().is_dirty());
+                    }
+                    Err(e) => log::error!("Failed to save course: {e}"),
+                }
+            },
+            AUTO_SAVE_DELAY,
+        );
+    });
 
     view! {
         cx,
         <div class="flex flex-col">
+            <div class="flex justify-between items-baseline py-2">
+                <h2 class="text-lg font-medium text-gray-900">{name}</h2>
+                <span class="text-sm text-gray-500">
+                    {move || if dirty.get() { "Unsaved changes" } else { "Saved" }}
+                </span>
+                <span class="text-sm text-gray-900">"Average: " {average_text}</span>
+            </div>
             <div class="overflow-x-auto sm:-mx-6 lg:-mx-8">
                 <div class="py-2 inline-block min-w-full sm:px-6 lg:px-8">
                     <div class="overflow-hidden">
@@ -32,9 +85,19 @@ pub fn CourseTable(cx: Scope, course: Course) -> impl IntoView {
                             <For
                                 each=assigns
                                 key=|a: &Assignment| a.name().to_owned()
-                                view=move |a: Assignment| view! { cx, <TableElement assignment=a /> }
+                                view=move |a: Assignment| view! { cx, <TableElement name=a.name().to_owned() assigns set_assigns /> }
                             />
                             </tbody>
+                            <tfoot class="bg-slate-100 border-t">
+                                <tr>
+                                    <td class="text-sm font-medium text-gray-900 px-6 py-4" colspan="3">
+                                        "Average"
+                                    </td>
+                                    <td class="text-sm font-medium text-gray-900 px-6 py-4">
+                                        {average_text}
+                                    </td>
+                                </tr>
+                            </tfoot>
                         </table>
                     </div>
                 </div>
@@ -44,22 +107,211 @@ pub fn CourseTable(cx: Scope, course: Course) -> impl IntoView {
 }
 
 #[component]
-fn TableElement(cx: Scope, assignment: Assignment) -> impl IntoView {
+fn TableElement(
+    cx: Scope,
+    name: String,
+    assigns: ReadSignal<Assignments>,
+    set_assigns: WriteSignal<Assignments>,
+) -> impl IntoView {
+    // each cell reads from this row's assignment, so the row updates itself on every edit
+    let assignment = {
+        let name = name.clone();
+        create_memo(cx, move |_| {
+            assigns.with(|assigns| assigns.iter().find(|a| a.name() == name).cloned())
+        })
+    };
+    let field =
+        move |f: fn(&Assignment) -> Option<u32>| assignment.with(|a| a.as_ref().and_then(f));
+    let mark_text = move || {
+        field(Assignment::mark)
+            .map(|m| m.to_string())
+            .unwrap_or_default()
+    };
+
+    let (error, set_error) = create_signal(cx, None::<String>);
+    let on_mark_change = move |ev: web_sys::Event| {
+        let Some(mut a) = assignment.get() else {
+            return;
+        };
+
+        match apply_mark_input(&mut a, &event_target_value(&ev)) {
+            Ok(()) => {
+                set_error.set(None);
+                set_assigns.update(|assigns| {
+                    if let Some(slot) = assigns.iter_mut().find(|slot| slot.name() == a.name()) {
+                        *slot = a;
+                    }
+                });
+            }
+            Err(e) => {
+                // the model is unchanged, so show the mark that is actually stored
+                event_target::<web_sys::HtmlInputElement>(&ev).set_value(&mark_text());
+                set_error.set(Some(e.to_string()));
+            }
+        }
+    };
+
     view! {
         cx,
         <tr class="odd:bg-white even:bg-slate-50 border-b transition duration-300 ease-in-out hover:bg-gray-100">
             <td class="text-sm text-gray-900 font-light px-6 py-4 whitespace-nowrap">
-                {assignment.name().to_owned()}
+                {name}
             </td>
             <td class="text-sm text-gray-900 font-light px-6 py-4 whitespace-nowrap">
-                {format!("{:?}", assignment.mark())}
+                <input
+                    type="number"
+                    min="0"
+                    max="100"
+                    class="w-16 border rounded px-1"
+                    prop:value=mark_text
+                    on:change=on_mark_change
+                />
+                <span class="block text-xs text-red-600">{move || error.get()}</span>
             </td>
             <td class="text-sm text-gray-900 font-light px-6 py-4 whitespace-nowrap">
-                {format!("{:?}", assignment.weight())}
+                {move || format_percent(field(Assignment::weight))}
             </td>
             <td class="text-sm text-gray-900 font-light px-6 py-4 whitespace-nowrap">
-                {format!("{:?}", assignment.percentage())}
+                {move || format_percent(field(Assignment::percentage))}
             </td>
         </tr>
     }
 }
+
+/// Applies the text of a mark input to `assignment`, clearing the mark if the text is blank.
+///
+/// # Errors
+/// The text is not a whole number, or is not a valid mark.
+fn apply_mark_input(assignment: &mut Assignment, input: &str) -> anyhow::Result<()> {
+    let input = input.trim();
+    if input.is_empty() {
+        assignment.clear_mark();
+        return Ok(());
+    }
+
+    let mark = input
+        .parse::<u32>()
+        .map_err(|_| anyhow!("`{input}` is not a whole number"))?;
+    assignment.set_mark(mark)?;
+    Ok(())
+}
+
+/// Tracks edits that are yet to be saved, so that only the latest edit is saved.
+#[derive(Debug, Default)]
+struct SaveState {
+    latest_edit: u32,
+    saved_edit: u32,
+}
+
+impl SaveState {
+    /// Records an edit, returning its id.
+    fn edit(&mut self) -> u32 {
+        self.latest_edit = self.latest_edit.wrapping_add(1);
+        self.latest_edit
+    }
+
+    /// Returns `true` if `edit` is the latest edit, so is the one to save.
+    fn is_latest(&self, edit: u32) -> bool {
+        self.latest_edit == edit
+    }
+
+    /// Records that `edit` has been saved.
+    fn saved(&mut self, edit: u32) {
+        self.saved_edit = edit;
+    }
+
+    /// Returns `true` if there are edits that are yet to be saved.
+    fn is_dirty(&self) -> bool {
+        self.latest_edit != self.saved_edit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_by_default() {
+        assert!(!SaveState::default().is_dirty());
+    }
+
+    #[test]
+    fn edit_makes_dirty() {
+        let mut state = SaveState::default();
+        state.edit();
+        assert!(state.is_dirty());
+    }
+
+    #[test]
+    fn saving_latest_edit_makes_clean() {
+        let mut state = SaveState::default();
+        let edit = state.edit();
+        assert!(state.is_latest(edit));
+        state.saved(edit);
+        assert!(!state.is_dirty());
+    }
+
+    #[test]
+    fn later_edit_supersedes_earlier() {
+        let mut state = SaveState::default();
+        let first = state.edit();
+        let second = state.edit();
+        assert!(!state.is_latest(first));
+        assert!(state.is_latest(second));
+    }
+
+    #[test]
+    fn saving_earlier_edit_stays_dirty() {
+        let mut state = SaveState::default();
+        let first = state.edit();
+        state.edit();
+        state.saved(first);
+        assert!(state.is_dirty());
+    }
+
+    #[test]
+    fn edit_after_save_makes_dirty() {
+        let mut state = SaveState::default();
+        let edit = state.edit();
+        state.saved(edit);
+        state.edit();
+        assert!(state.is_dirty());
+    }
+
+    fn assignment() -> Assignment {
+        Assignment::builder()
+            .name("A")
+            .mark(80)
+            .weight(50)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn mark_input_sets_mark() {
+        let mut a = Assignment::new("A").unwrap();
+        apply_mark_input(&mut a, " 75 ").unwrap();
+        assert_eq!(a.mark(), Some(75));
+        assert_eq!(a.status(), Status::Marked);
+    }
+
+    #[test]
+    fn blank_mark_input_clears_mark() {
+        let mut a = assignment();
+        apply_mark_input(&mut a, "").unwrap();
+        assert_eq!(a.mark(), None);
+        assert_eq!(a.percentage(), None);
+    }
+
+    #[test]
+    fn rejected_mark_input_leaves_assignment_unchanged() {
+        for input in ["abc", "-5", "7.5", "150"] {
+            let mut a = assignment();
+            assert!(
+                apply_mark_input(&mut a, input).is_err(),
+                "accepted `{input}`"
+            );
+            assert_eq!(a, assignment());
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod app;
 use app::*;
 
 pub mod components;
+pub mod store;
 
 #[wasm_bindgen(start)]
 pub fn main() {
@@ -0,0 +1,273 @@
+use anyhow::anyhow;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracker_core::*;
+
+/// Key used to store the courses in `localStorage`.
+const STORAGE_KEY: &str = "courses";
+
+/// Persistence for the user's [Course]s.
+pub trait Store {
+    /// Load the saved [Course]s, or [None] if no [Course]s have been saved.
+    ///
+    /// # Errors
+    /// The saved [Course]s could not be read or are invalid.
+    fn load(&self) -> anyhow::Result<Option<Vec<Course>>>;
+
+    /// Save the [Course]s, replacing any previously saved [Course]s.
+    ///
+    /// # Errors
+    /// The [Course]s could not be saved.
+    fn save(&self, courses: &[Course]) -> anyhow::Result<()>;
+}
+
+/// Validates [Course]s loaded from storage.
+///
+/// Deserialising bypasses the checks made when adding assignments, and stored data may
+/// have been edited by hand.
+///
+/// # Errors
+/// A course has invalid credits, an assignment is invalid, or a course's assignments have
+/// duplicate names or too much weight.
+fn validate_courses(courses: Vec<Course>) -> anyhow::Result<Vec<Course>> {
+    courses
+        .into_iter()
+        .map(|mut course| {
+            course.is_valid()?;
+            for assignment in course.assignments.iter_mut() {
+                assignment.repair_status();
+                assignment.is_valid()?;
+            }
+            course.assignments = Assignments::try_from_iter(course.assignments)?;
+            Ok(course)
+        })
+        .collect()
+}
+
+/// Parses and validates [Course]s saved as JSON.
+///
+/// # Errors
+/// The JSON is malformed, or the [Course]s are invalid (see [validate_courses]).
+fn parse_courses(json: &str) -> anyhow::Result<Vec<Course>> {
+    validate_courses(serde_json::from_str(json)?)
+}
+
+/// Loads the saved [Course]s from `store`, or `fallback` if no [Course]s have been saved.
+///
+/// Also returns the [Store] that edits should be saved to. If the saved [Course]s could not
+/// be read, saving `fallback` would overwrite them, so the returned [Store] refuses to save.
+pub fn load_or_else(
+    store: Rc<dyn Store>,
+    fallback: impl FnOnce() -> Vec<Course>,
+) -> (Vec<Course>, Rc<dyn Store>) {
+    match store.load() {
+        Ok(Some(courses)) => (courses, store),
+        Ok(None) => (fallback(), store),
+        Err(e) => {
+            log::error!("Failed to load courses, changes will not be saved: {e}");
+            (fallback(), Rc::new(ReadOnlyStore))
+        }
+    }
+}
+
+/// [Store] backed by the browser's `localStorage`.
+#[derive(Debug, Default)]
+pub struct LocalStorageStore;
+
+impl LocalStorageStore {
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
+
+impl Store for LocalStorageStore {
+    fn load(&self) -> anyhow::Result<Option<Vec<Course>>> {
+        let Some(storage) = Self::storage() else {
+            return Ok(None);
+        };
+
+        let json = storage
+            .get_item(STORAGE_KEY)
+            .map_err(|e| anyhow!("{e:?}"))?;
+        json.as_deref().map(parse_courses).transpose()
+    }
+
+    fn save(&self, courses: &[Course]) -> anyhow::Result<()> {
+        let storage = Self::storage().ok_or_else(|| anyhow!("localStorage is unavailable"))?;
+        let json = serde_json::to_string(courses)?;
+        storage
+            .set_item(STORAGE_KEY, &json)
+            .map_err(|e| anyhow!("{e:?}"))
+    }
+}
+
+/// In-memory [Store], used for testing without a browser.
+///
+/// Holds the saved JSON so that it behaves like [LocalStorageStore].
+#[derive(Debug, Default)]
+pub struct MemStore {
+    json: RefCell<Option<String>>,
+}
+
+impl MemStore {
+    /// Creates a [MemStore] with `json` already saved.
+    pub fn with_json(json: &str) -> Self {
+        Self {
+            json: RefCell::new(Some(json.to_owned())),
+        }
+    }
+}
+
+impl Store for MemStore {
+    fn load(&self) -> anyhow::Result<Option<Vec<Course>>> {
+        self.json.borrow().as_deref().map(parse_courses).transpose()
+    }
+
+    fn save(&self, courses: &[Course]) -> anyhow::Result<()> {
+        *self.json.borrow_mut() = Some(serde_json::to_string(courses)?);
+        Ok(())
+    }
+}
+
+/// [Store] which refuses to save, used when the saved [Course]s could not be read.
+#[derive(Debug, Default)]
+struct ReadOnlyStore;
+
+impl Store for ReadOnlyStore {
+    fn load(&self) -> anyhow::Result<Option<Vec<Course>>> {
+        Ok(None)
+    }
+
+    fn save(&self, _courses: &[Course]) -> anyhow::Result<()> {
+        Err(anyhow!(
+            "saved courses could not be read, so changes are not saved"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course() -> Course {
+        let assignments = Assignments::from([
+            Assignment::builder()
+                .name("A")
+                .mark(80)
+                .weight(50)
+                .build()
+                .unwrap(),
+            Assignment::builder().name("B").weight(50).build().unwrap(),
+        ]);
+        Course::with_assignments("Course", assignments)
+    }
+
+    #[test]
+    fn mem_store_is_empty_by_default() {
+        assert_eq!(MemStore::default().load().unwrap(), None);
+    }
+
+    #[test]
+    fn mem_store_save_then_load() {
+        let store = MemStore::default();
+        let courses = vec![course(), Course::new("Empty")];
+        store.save(&courses).unwrap();
+        assert_eq!(store.load().unwrap(), Some(courses));
+    }
+
+    #[test]
+    fn validate_courses_accepts_valid_courses() {
+        let courses = vec![course()];
+        assert_eq!(validate_courses(courses.clone()).unwrap(), courses);
+    }
+
+    #[test]
+    fn validate_courses_rejects_duplicate_names() {
+        let a = Assignment::new("A").unwrap();
+        let assignments = Assignments::from([a.clone(), a]);
+        let courses = vec![Course::with_assignments("Course", assignments)];
+        assert!(validate_courses(courses).is_err());
+    }
+
+    #[test]
+    fn validate_courses_rejects_excess_weight() {
+        let assignments = Assignments::from([
+            Assignment::builder().name("A").weight(60).build().unwrap(),
+            Assignment::builder().name("B").weight(60).build().unwrap(),
+        ]);
+        let courses = vec![Course::with_assignments("Course", assignments)];
+        assert!(validate_courses(courses).is_err());
+    }
+
+    #[test]
+    fn validate_courses_rejects_deserialised_duplicates() {
+        let json = serde_json::to_string(&[Course::with_assignments(
+            "Course",
+            Assignments::from([Assignment::new("A").unwrap(), Assignment::new("A").unwrap()]),
+        )])
+        .unwrap();
+        let courses: Vec<Course> = serde_json::from_str(&json).unwrap();
+        assert!(validate_courses(courses).is_err());
+    }
+
+    #[test]
+    fn validate_courses_keeps_provisional_mark() {
+        let mut a = Assignment::builder().name("A").weight(50).build().unwrap();
+        a.set_status(Status::Submitted).unwrap();
+        a.set_mark_keeping_status(80).unwrap();
+        let courses = vec![Course::with_assignments("Course", Assignments::from([a]))];
+        assert_eq!(validate_courses(courses.clone()).unwrap(), courses);
+    }
+
+    #[test]
+    fn validate_courses_rejects_invalid_credits() {
+        for credits in ["0.0", "-1.0"] {
+            let json =
+                format!(r#"[{{"name":"A","assignments":{{"inner":[]}},"credits":{credits}}}]"#);
+            let courses: Vec<Course> = serde_json::from_str(&json).unwrap();
+            assert!(
+                validate_courses(courses).is_err(),
+                "accepted credits {credits}"
+            );
+        }
+    }
+
+    #[test]
+    fn mem_store_rejects_invalid_json() {
+        assert!(MemStore::with_json("not json").load().is_err());
+    }
+
+    #[test]
+    fn load_or_else_uses_fallback_when_nothing_saved() {
+        let store = Rc::new(MemStore::default());
+        let (courses, saver) = load_or_else(store.clone(), || vec![course()]);
+        assert_eq!(courses, vec![course()]);
+        saver.save(&courses).unwrap();
+        assert_eq!(store.load().unwrap(), Some(courses));
+    }
+
+    #[test]
+    fn load_or_else_loads_saved_courses() {
+        let store = Rc::new(MemStore::default());
+        store.save(&[course()]).unwrap();
+        let (courses, _) = load_or_else(store, || vec![Course::new("Fallback")]);
+        assert_eq!(courses, vec![course()]);
+    }
+
+    #[test]
+    fn unreadable_data_is_never_overwritten() {
+        let invalid = serde_json::to_string(&[Course::with_assignments(
+            "Course",
+            Assignments::from([Assignment::new("A").unwrap(), Assignment::new("A").unwrap()]),
+        )])
+        .unwrap();
+
+        for json in ["not json", invalid.as_str()] {
+            let store = Rc::new(MemStore::with_json(json));
+            let (courses, saver) = load_or_else(store.clone(), || vec![course()]);
+            assert_eq!(courses, vec![course()]);
+            assert!(saver.save(&courses).is_err());
+            assert_eq!(store.json.borrow().as_deref(), Some(json));
+        }
+    }
+}
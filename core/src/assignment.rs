@@ -1,20 +1,66 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
 use thiserror::Error;
 
+use crate::Status;
+
+const DEFAULT_NAME_LENGTH: RangeInclusive<usize> = 1..=60;
+
 /// Representation of an [Assignment].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Assignment {
     name: String,
     mark: Option<u32>,
     weight: Option<u32>,
     percentage: Option<u32>,
+    status: Status,
 }
 
+/// Errors from creating or modifying an [Assignment].
+///
+/// New variants may be added, so matches outside this crate need a wildcard arm:
+///
+/// ```
+/// use tracker_core::AssignmentError;
+///
+/// fn describe(e: &AssignmentError) -> &'static str {
+///     match e {
+///         AssignmentError::NotPercentage(_) => "not a percentage",
+///         AssignmentError::NoName => "no name",
+///         _ => "other",
+///     }
+/// }
+/// ```
+///
+/// Listing every variant without a wildcard does not compile:
+///
+/// ```compile_fail
+/// use tracker_core::AssignmentError;
+///
+/// fn describe(e: &AssignmentError) -> &'static str {
+///     match e {
+///         AssignmentError::NotPercentage(_) => "not a percentage",
+///         AssignmentError::NoName => "no name",
+///         AssignmentError::NameLength { .. } => "bad name length",
+///         AssignmentError::InvalidStatus(_) => "invalid status",
+///         AssignmentError::InvalidPercentage(_) => "invalid percentage",
+///     }
+/// }
+/// ```
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum AssignmentError {
     #[error("the Value `{0}` is not within a percentage range")]
     NotPercentage(u32),
     #[error("assignment name was not provided")]
     NoName,
+    #[error("assignment name length `{len}` is not within `{min}..={max}`")]
+    NameLength { len: usize, min: usize, max: usize },
+    #[error("the status `{0:?}` is inconsistent with the assignment mark")]
+    InvalidStatus(Status),
+    #[error("the percentage `{0:?}` is inconsistent with the assignment mark and weight")]
+    InvalidPercentage(Option<u32>),
 }
 
 impl Assignment {
@@ -23,11 +69,30 @@ impl Assignment {
     }
 
     /// Create a new [Assignment] with a name.
-    pub fn new(name: &str) -> Self {
-        Self {
+    ///
+    /// # Errors
+    /// - `name` is empty or only contains whitespace.
+    /// - `name` is not between 1 and 60 characters long.
+    pub fn new(name: &str) -> Result<Self, AssignmentError> {
+        Self::with_name_length(name, DEFAULT_NAME_LENGTH)
+    }
+
+    /// Create a new [Assignment] with a name, where the length of the name (in characters)
+    /// must be within `name_length`.
+    ///
+    /// # Errors
+    /// - `name` is empty or only contains whitespace.
+    /// - `name` is not within `name_length` characters long.
+    pub fn with_name_length(
+        name: &str,
+        name_length: RangeInclusive<usize>,
+    ) -> Result<Self, AssignmentError> {
+        validate_name(name, &name_length)?;
+
+        Ok(Self {
             name: name.to_owned(),
             ..Default::default()
-        }
+        })
     }
 
     /// Get the name of the [Assignment].
@@ -50,11 +115,32 @@ impl Assignment {
         self.percentage
     }
 
+    /// Get the completion [Status] of the [Assignment].
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
     /// Set the mark for the [Assignment].
+    ///
+    /// The status is set to [Status::Marked].
     /// 
     /// # Errors
     /// - `mark` is greater than 100.
     pub fn set_mark(&mut self, mark: u32) -> Result<(), AssignmentError> {
+        self.set_mark_keeping_status(mark)?;
+        self.status = Status::Marked;
+        Ok(())
+    }
+
+    /// Set a provisional mark for the [Assignment] without changing its [Status].
+    ///
+    /// Unlike [Assignment::set_mark], the [Assignment] may then have a mark while its
+    /// status is not [Status::Marked], e.g. a [Status::Complete] assignment that is still
+    /// being treated as in progress.
+    ///
+    /// # Errors
+    /// - `mark` is greater than 100.
+    pub fn set_mark_keeping_status(&mut self, mark: u32) -> Result<(), AssignmentError> {
         if mark > 100 {
             return Err(AssignmentError::NotPercentage(mark));
         }
@@ -63,6 +149,33 @@ impl Assignment {
         Ok(())
     }
 
+    /// Remove the mark from the [Assignment].
+    ///
+    /// A [Status::Marked] assignment becomes [Status::Incomplete], as it is for
+    /// [Assignment::repair_status]; any other status is kept.
+    pub fn clear_mark(&mut self) {
+        self.mark = None;
+        self.update_percentage();
+        if self.status == Status::Marked {
+            self.status = Status::Incomplete;
+        }
+    }
+
+    /// Set the [Status] for the [Assignment].
+    ///
+    /// Only [Status::Marked] requires a mark; any status may have a provisional mark
+    /// (see [Assignment::set_mark_keeping_status]).
+    ///
+    /// # Errors
+    /// - `status` is [Status::Marked] and the [Assignment] has no mark.
+    pub fn set_status(&mut self, status: Status) -> Result<(), AssignmentError> {
+        if status == Status::Marked && self.mark.is_none() {
+            return Err(AssignmentError::InvalidStatus(status));
+        }
+        self.status = status;
+        Ok(())
+    }
+
     /// Set the weight for the [Assignment].
     /// 
     /// # Errors
@@ -76,11 +189,134 @@ impl Assignment {
         Ok(())
     }
 
-    fn update_percentage(&mut self) {
-        if let (Some(mark), Some(weight)) = (self.mark, self.weight) {
-            self.percentage = Some(mark * weight / 100);
+    /// Coerces the [Status] to be consistent with the mark.
+    ///
+    /// [Status::Marked] without a mark becomes [Status::Incomplete]. Any other status is
+    /// left as is, so a provisional mark keeps its status (see
+    /// [Assignment::set_mark_keeping_status]).
+    pub fn repair_status(&mut self) {
+        let status = match (self.mark, self.status) {
+            (None, Status::Marked) => Status::Incomplete,
+            (_, status) => status,
+        };
+
+        if status != self.status {
+            log::info!(
+                "Repaired status of `{}`: {:?} -> {:?}",
+                self.name,
+                self.status,
+                status
+            );
+            self.status = status;
         }
     }
+
+    /// Checks that the [Assignment] is valid as a whole.
+    ///
+    /// Useful after deserialising, which bypasses the validation done by the setters.
+    /// The name length is checked against the default range used by [Assignment::new]; use
+    /// [Assignment::is_valid_with] for assignments created with a custom range.
+    ///
+    /// # Errors
+    /// - `name` is empty or only contains whitespace.
+    /// - `name` is not between 1 and 60 characters long.
+    /// - `mark` or `weight` is greater than 100.
+    /// - `percentage` does not match `mark * weight / 100`.
+    /// - `status` is [Status::Marked] and the [Assignment] has no mark.
+    pub fn is_valid(&self) -> Result<(), AssignmentError> {
+        self.is_valid_with(&DEFAULT_NAME_LENGTH)
+    }
+
+    /// Checks that the [Assignment] is valid as a whole, where the length of the name (in
+    /// characters) must be within `name_length`.
+    ///
+    /// See [Assignment::with_name_length] and [Assignment::is_valid].
+    ///
+    /// # Errors
+    /// - `name` is empty or only contains whitespace.
+    /// - `name` is not within `name_length` characters long.
+    /// - `mark` or `weight` is greater than 100.
+    /// - `percentage` does not match `mark * weight / 100`.
+    /// - `status` is [Status::Marked] and the [Assignment] has no mark.
+    pub fn is_valid_with(
+        &self,
+        name_length: &RangeInclusive<usize>,
+    ) -> Result<(), AssignmentError> {
+        validate_name(&self.name, name_length)?;
+
+        for value in [self.mark, self.weight].into_iter().flatten() {
+            if value > 100 {
+                return Err(AssignmentError::NotPercentage(value));
+            }
+        }
+
+        if self.percentage != self.calculate_percentage() {
+            return Err(AssignmentError::InvalidPercentage(self.percentage));
+        }
+
+        if self.status == Status::Marked && self.mark.is_none() {
+            return Err(AssignmentError::InvalidStatus(self.status));
+        }
+
+        Ok(())
+    }
+
+    /// Formats the [Assignment] as a row with the name padded (or truncated) to `name_width`
+    /// characters, followed by the right-aligned mark, weight, and percentage.
+    pub fn display_aligned(&self, name_width: usize) -> String {
+        let name: String = self.name.chars().take(name_width).collect();
+        format!(
+            "{name:<name_width$} {:>4} {:>4} {:>4}",
+            format_percent(self.mark),
+            format_percent(self.weight),
+            format_percent(self.percentage),
+        )
+    }
+
+    fn update_percentage(&mut self) {
+        self.percentage = self.calculate_percentage();
+    }
+
+    fn calculate_percentage(&self) -> Option<u32> {
+        let (mark, weight) = self.mark.zip(self.weight)?;
+        Some(mark * weight / 100)
+    }
+}
+
+/// Check that `name` is not blank and its length (in characters) is within `name_length`.
+fn validate_name(name: &str, name_length: &RangeInclusive<usize>) -> Result<(), AssignmentError> {
+    if name.trim().is_empty() {
+        return Err(AssignmentError::NoName);
+    }
+
+    let len = name.chars().count();
+    if !name_length.contains(&len) {
+        return Err(AssignmentError::NameLength {
+            len,
+            min: *name_length.start(),
+            max: *name_length.end(),
+        });
+    }
+
+    Ok(())
+}
+
+impl Display for Assignment {
+    /// Formats the [Assignment] as `name — mark% (weight weight%)`, using `—` for missing values.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} — {} (weight {})",
+            self.name,
+            format_percent(self.mark),
+            format_percent(self.weight)
+        )
+    }
+}
+
+/// Formats an optional percentage, using `—` when there is no value.
+pub fn format_percent(value: Option<u32>) -> String {
+    value.map_or_else(|| String::from("—"), |v| format!("{v}%"))
 }
 
 impl Default for Assignment {
@@ -90,13 +326,16 @@ impl Default for Assignment {
             mark: None,
             weight: None,
             percentage: None,
+            status: Status::default(),
         }
     }
 }
 
 #[derive(Debug, Default)]
+#[must_use]
 pub struct AssignmentBuilder {
     name: Option<String>,
+    name_length: Option<RangeInclusive<usize>>,
     mark: Option<u32>,
     weight: Option<u32>,
     // percentage: calculated when building the assignment
@@ -106,14 +345,15 @@ impl AssignmentBuilder {
     /// Builds and returns an [Assignment].
     ///
     /// # Errors
-    /// `name` is not provided.
+    /// `name` is not provided, is empty, or its length is out of bounds.
     /// `mark` or `weight` is provided and *not* between 0 and 100.
     pub fn build(self) -> Result<Assignment, AssignmentError> {
         let Some(name) = self.name else {
             return Err(AssignmentError::NoName);
         };
 
-        let mut a = Assignment::new(&name);
+        let name_length = self.name_length.unwrap_or(DEFAULT_NAME_LENGTH);
+        let mut a = Assignment::with_name_length(&name, name_length)?;
 
         if let Some(mark) = self.mark {
             a.set_mark(mark)?;
@@ -123,22 +363,32 @@ impl AssignmentBuilder {
             a.set_weight(weight)?;
         }
 
-        return Ok(a);
+        Ok(a)
     }
 
     /// Provide a name for the [Assignment].
-    pub fn name(&mut self, name: &str) -> &mut Self {
+    pub fn name(mut self, name: &str) -> Self {
         self.name = Some(name.to_owned());
         self
     }
 
+    /// Provide the allowed length (in characters) of the name for the [Assignment].
+    ///
+    /// Defaults to between 1 and 60 characters.
+    ///
+    /// Enforcement occurs after calling [AssignmentBuilder::build].
+    pub fn name_length(mut self, min: usize, max: usize) -> Self {
+        self.name_length = Some(min..=max);
+        self
+    }
+
     /// Provide a mark for the [Assignment].
     ///
     /// # Constraints
     /// `mark` must be between 0 and 100.
     ///
     /// Enforcement occurs after calling [AssignmentBuilder::build].
-    pub fn mark(&mut self, mark: u32) -> &mut Self {
+    pub fn mark(mut self, mark: u32) -> Self {
         self.mark = Some(mark);
         self
     }
@@ -149,8 +399,368 @@ impl AssignmentBuilder {
     /// `weight` must be between 0 and 100.
     ///
     /// Enforcement occurs after calling [AssignmentBuilder::build].
-    pub fn weight(&mut self, weight: u32) -> &mut Self {
+    pub fn weight(mut self, weight: u32) -> Self {
         self.weight = Some(weight);
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_empty_name() {
+        assert!(matches!(Assignment::new(""), Err(AssignmentError::NoName)));
+    }
+
+    #[test]
+    fn new_rejects_whitespace_name() {
+        assert!(matches!(Assignment::new(" \t\n"), Err(AssignmentError::NoName)));
+    }
+
+    #[test]
+    fn builder_rejects_missing_name() {
+        assert!(matches!(
+            AssignmentBuilder::default().build(),
+            Err(AssignmentError::NoName)
+        ));
+    }
+
+    #[test]
+    fn new_accepts_name_length_boundaries() {
+        assert!(Assignment::new("A").is_ok());
+        assert!(Assignment::new(&"A".repeat(60)).is_ok());
+    }
+
+    #[test]
+    fn new_rejects_name_longer_than_default() {
+        assert!(matches!(
+            Assignment::new(&"A".repeat(61)),
+            Err(AssignmentError::NameLength {
+                len: 61,
+                min: 1,
+                max: 60
+            })
+        ));
+        assert!(Assignment::new(&"A".repeat(500)).is_err());
+    }
+
+    #[test]
+    fn with_name_length_enforces_custom_range() {
+        assert!(Assignment::with_name_length("Abc", 3..=20).is_ok());
+        assert!(Assignment::with_name_length(&"A".repeat(20), 3..=20).is_ok());
+        assert!(Assignment::with_name_length("Ab", 3..=20).is_err());
+        assert!(Assignment::with_name_length(&"A".repeat(21), 3..=20).is_err());
+    }
+
+    #[test]
+    fn name_length_counts_characters() {
+        let name = "Résumé Проект";
+        assert!(name.len() > 20);
+        assert!(Assignment::with_name_length(name, 3..=20).is_ok());
+    }
+
+    #[test]
+    fn builder_enforces_name_length() {
+        let result = Assignment::builder().name("Ab").name_length(3, 20).build();
+        assert!(matches!(result, Err(AssignmentError::NameLength { .. })));
+    }
+
+    #[test]
+    fn display_populated() {
+        let a = Assignment::builder()
+            .name("Exam")
+            .mark(80)
+            .weight(25)
+            .build()
+            .unwrap();
+        assert_eq!(a.to_string(), "Exam — 80% (weight 25%)");
+    }
+
+    #[test]
+    fn display_empty() {
+        let a = Assignment::new("Exam").unwrap();
+        assert_eq!(a.to_string(), "Exam — — (weight —)");
+    }
+
+    #[test]
+    fn set_mark_marks_status() {
+        let mut a = Assignment::new("A").unwrap();
+        assert_eq!(a.status(), Status::Incomplete);
+        a.set_mark(50).unwrap();
+        assert_eq!(a.status(), Status::Marked);
+    }
+
+    #[test]
+    fn set_status_marked_requires_mark() {
+        let mut a = Assignment::new("A").unwrap();
+        assert!(matches!(
+            a.set_status(Status::Marked),
+            Err(AssignmentError::InvalidStatus(Status::Marked))
+        ));
+        a.set_status(Status::Complete).unwrap();
+        assert_eq!(a.status(), Status::Complete);
+    }
+
+    #[test]
+    fn format_percent_handles_missing_values() {
+        assert_eq!(format_percent(Some(25)), "25%");
+        assert_eq!(format_percent(None), "—");
+    }
+
+    #[test]
+    fn set_mark_keeping_status_keeps_status() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_status(Status::Complete).unwrap();
+        a.set_mark_keeping_status(50).unwrap();
+        assert_eq!(a.mark(), Some(50));
+        assert_eq!(a.status(), Status::Complete);
+        assert!(a.set_mark_keeping_status(101).is_err());
+    }
+
+    #[test]
+    fn provisional_mark_can_move_through_statuses() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_mark_keeping_status(50).unwrap();
+        assert_eq!(a.status(), Status::Incomplete);
+        a.set_status(Status::Complete).unwrap();
+        a.set_status(Status::Marked).unwrap();
+        assert_eq!(a.status(), Status::Marked);
+        assert!(a.is_valid().is_ok());
+    }
+
+    #[test]
+    fn set_mark_couples_status() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_status(Status::Complete).unwrap();
+        a.set_mark(50).unwrap();
+        assert_eq!(a.status(), Status::Marked);
+    }
+
+    #[test]
+    fn display_aligned_pads_short_names() {
+        let a = Assignment::builder()
+            .name("Quiz")
+            .mark(80)
+            .weight(25)
+            .build()
+            .unwrap();
+        assert_eq!(a.display_aligned(8), "Quiz      80%  25%  20%");
+    }
+
+    #[test]
+    fn display_aligned_truncates_long_names() {
+        let a = Assignment::new("Résumé Project").unwrap();
+        assert_eq!(a.display_aligned(6), "Résumé    —    —    —");
+    }
+
+    fn from_json(json: &str) -> Assignment {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn is_valid_accepts_valid_assignment() {
+        let a = Assignment::builder()
+            .name("A")
+            .mark(80)
+            .weight(50)
+            .build()
+            .unwrap();
+        assert!(a.is_valid().is_ok());
+        assert!(Assignment::new("A").unwrap().is_valid().is_ok());
+    }
+
+    #[test]
+    fn is_valid_rejects_deserialised_out_of_range_mark() {
+        let a = from_json(
+            r#"{"name":"A","mark":150,"weight":null,"percentage":null,"status":"Marked"}"#,
+        );
+        assert!(matches!(
+            a.is_valid(),
+            Err(AssignmentError::NotPercentage(150))
+        ));
+    }
+
+    #[test]
+    fn is_valid_rejects_deserialised_long_name() {
+        let json = format!(
+            r#"{{"name":"{}","mark":null,"weight":null,"percentage":null,"status":"Incomplete"}}"#,
+            "A".repeat(500)
+        );
+        assert!(matches!(
+            from_json(&json).is_valid(),
+            Err(AssignmentError::NameLength { len: 500, .. })
+        ));
+    }
+
+    #[test]
+    fn is_valid_rejects_deserialised_inconsistent_percentage() {
+        let a =
+            from_json(r#"{"name":"A","mark":80,"weight":50,"percentage":90,"status":"Marked"}"#);
+        assert!(matches!(
+            a.is_valid(),
+            Err(AssignmentError::InvalidPercentage(Some(90)))
+        ));
+    }
+
+    #[test]
+    fn is_valid_rejects_deserialised_marked_without_mark() {
+        let a = from_json(
+            r#"{"name":"A","mark":null,"weight":null,"percentage":null,"status":"Marked"}"#,
+        );
+        assert!(matches!(
+            a.is_valid(),
+            Err(AssignmentError::InvalidStatus(Status::Marked))
+        ));
+    }
+
+    #[test]
+    fn repair_status_unmarks_marked_without_mark() {
+        let mut a = from_json(
+            r#"{"name":"A","mark":null,"weight":null,"percentage":null,"status":"Marked"}"#,
+        );
+        a.repair_status();
+        assert_eq!(a.status(), Status::Incomplete);
+    }
+
+    #[test]
+    fn repair_status_keeps_provisional_mark_status() {
+        for status in [
+            Status::Incomplete,
+            Status::Complete,
+            Status::Submitted,
+            Status::Late,
+        ] {
+            let mut a = Assignment::new("A").unwrap();
+            a.set_status(status).unwrap();
+            a.set_mark_keeping_status(80).unwrap();
+            a.repair_status();
+            assert_eq!(a.status(), status);
+            assert_eq!(a.mark(), Some(80));
+        }
+    }
+
+    #[test]
+    fn repair_status_keeps_marked_with_mark() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_mark(80).unwrap();
+        a.repair_status();
+        assert_eq!(a.status(), Status::Marked);
+    }
+
+    #[test]
+    fn submitted_and_late_are_valid_without_mark() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_status(Status::Submitted).unwrap();
+        assert_eq!(a.status(), Status::Submitted);
+        assert!(a.is_valid().is_ok());
+        a.set_status(Status::Late).unwrap();
+        assert_eq!(a.status(), Status::Late);
+        assert!(a.is_valid().is_ok());
+    }
+
+    #[test]
+    fn submitted_and_late_allow_provisional_mark() {
+        for status in [Status::Submitted, Status::Late] {
+            let mut a = Assignment::new("A").unwrap();
+            a.set_status(status).unwrap();
+            a.set_mark_keeping_status(70).unwrap();
+            assert_eq!(a.status(), status);
+            assert!(a.is_valid().is_ok());
+        }
+    }
+
+    #[test]
+    fn submitted_and_late_become_marked() {
+        for status in [Status::Submitted, Status::Late] {
+            let mut a = Assignment::new("A").unwrap();
+            a.set_status(status).unwrap();
+            a.set_mark(70).unwrap();
+            assert_eq!(a.status(), Status::Marked);
+        }
+    }
+
+    #[test]
+    fn builder_chains_in_one_expression() {
+        let a = Assignment::builder()
+            .name("x")
+            .mark(80)
+            .weight(25)
+            .build()
+            .unwrap();
+        assert_eq!(a.name(), "x");
+        assert_eq!(a.mark(), Some(80));
+        assert_eq!(a.weight(), Some(25));
+        assert_eq!(a.percentage(), Some(20));
+        assert_eq!(a.status(), Status::Marked);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_mark_and_weight() {
+        assert!(matches!(
+            Assignment::builder().name("x").mark(101).build(),
+            Err(AssignmentError::NotPercentage(101))
+        ));
+        assert!(matches!(
+            Assignment::builder().name("x").weight(101).build(),
+            Err(AssignmentError::NotPercentage(101))
+        ));
+    }
+
+    #[test]
+    fn clear_mark_unmarks() {
+        let mut a = Assignment::builder()
+            .name("A")
+            .mark(80)
+            .weight(50)
+            .build()
+            .unwrap();
+        a.clear_mark();
+        assert_eq!(a.mark(), None);
+        assert_eq!(a.percentage(), None);
+        assert_eq!(a.status(), Status::Incomplete);
+        assert!(a.is_valid().is_ok());
+    }
+
+    #[test]
+    fn clear_mark_keeps_provisional_status() {
+        let mut a = Assignment::new("A").unwrap();
+        a.set_status(Status::Submitted).unwrap();
+        a.set_mark_keeping_status(80).unwrap();
+        a.clear_mark();
+        assert_eq!(a.mark(), None);
+        assert_eq!(a.status(), Status::Submitted);
+    }
+
+    #[test]
+    fn is_valid_with_accepts_custom_name_length() {
+        let name = "a".repeat(80);
+        let a = Assignment::with_name_length(&name, 1..=100).unwrap();
+        assert!(a.is_valid_with(&(1..=100)).is_ok());
+        assert!(matches!(
+            a.is_valid(),
+            Err(AssignmentError::NameLength { len: 80, .. })
+        ));
+
+        let b = Assignment::builder()
+            .name(&name)
+            .name_length(1, 100)
+            .build()
+            .unwrap();
+        assert!(b.is_valid_with(&(1..=100)).is_ok());
+    }
+
+    #[test]
+    fn is_valid_with_rejects_name_outside_range() {
+        let a = Assignment::new("Ab").unwrap();
+        assert!(matches!(
+            a.is_valid_with(&(3..=20)),
+            Err(AssignmentError::NameLength {
+                len: 2,
+                min: 3,
+                max: 20
+            })
+        ));
+    }
+}
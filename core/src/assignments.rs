@@ -1,18 +1,48 @@
-use crate::Assignment;
+use crate::{Assignment, Status};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use thiserror::Error;
 
-const MAX_SUM_WEIGHT: u32 = 100;
+pub(crate) const MAX_SUM_WEIGHT: u32 = 100;
 
 /// Collection of [Assignment]s.
 ///
 /// Backed by a [VecDeque].
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Assignments {
     inner: VecDeque<Assignment>,
 }
 
+/// Errors from adding [Assignment]s to an [Assignments] collection.
+///
+/// New variants may be added, so matches outside this crate need a wildcard arm:
+///
+/// ```
+/// use tracker_core::AssignmentsError;
+///
+/// fn describe(e: &AssignmentsError) -> &'static str {
+///     match e {
+///         AssignmentsError::NonUniqueName(_) => "duplicate name",
+///         AssignmentsError::WeightsOutOfBounds(_) => "too much weight",
+///         _ => "other",
+///     }
+/// }
+/// ```
+///
+/// Listing every variant without a wildcard does not compile:
+///
+/// ```compile_fail
+/// use tracker_core::AssignmentsError;
+///
+/// fn describe(e: &AssignmentsError) -> &'static str {
+///     match e {
+///         AssignmentsError::NonUniqueName(_) => "duplicate name",
+///         AssignmentsError::WeightsOutOfBounds(_) => "too much weight",
+///     }
+/// }
+/// ```
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum AssignmentsError {
     #[error("Assignment with name {0} already exists")]
     NonUniqueName(String),
@@ -26,6 +56,22 @@ impl Assignments {
         Self { inner: VecDeque::new() }
     }
 
+    /// Creates a new [Assignments] collection from an iterator, validating each [Assignment].
+    ///
+    /// # Errors
+    /// Two assignments have the same name.
+    /// The sum of all assignment weights exceeds 100.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, AssignmentsError>
+    where
+        I: IntoIterator<Item = Assignment>,
+    {
+        let mut assignments = Self::new();
+        for assignment in iter {
+            assignments.push_back(assignment)?;
+        }
+        Ok(assignments)
+    }
+
     /// Returns an [Assignment] at the given index.
     pub fn get(&self, index: usize) -> Option<&Assignment> {
         self.inner.get(index)
@@ -35,18 +81,78 @@ impl Assignments {
         self.inner.get_mut(index)
     }
 
+    /// Returns an iterator over the [Assignment]s in the collection.
+    ///
+    /// See [VecDeque::iter].
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, Assignment> {
+        self.inner.iter()
+    }
+
+    /// Returns an iterator that allows modifying each [Assignment] in the collection.
+    ///
+    /// See [VecDeque::iter_mut].
+    pub fn iter_mut(&mut self) -> std::collections::vec_deque::IterMut<'_, Assignment> {
+        self.inner.iter_mut()
+    }
+
+    /// Returns `true` if the collection contains an [Assignment] with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter().any(|a| a.name() == name)
+    }
+
+    /// Returns the index of the [Assignment] with the given name.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.iter().position(|a| a.name() == name)
+    }
+
+    /// Returns the [Assignment]s that have been completed, whether or not they are marked.
+    pub fn completed(&self) -> Vec<&Assignment> {
+        self.iter().completed().collect()
+    }
+
+    /// Returns the [Assignment]s that are yet to be completed.
+    pub fn pending(&self) -> Vec<&Assignment> {
+        self.iter().in_status(Status::Incomplete).collect()
+    }
+
+    /// Returns the sum of the weights of all [Assignment]s in the collection.
+    pub fn total_weight(&self) -> u32 {
+        self.iter().filter_map(|a| a.weight()).sum()
+    }
+
+    /// Returns the average mark of the collection, weighted by each [Assignment]'s weight.
+    ///
+    /// Only [Assignment]s with both a mark and a weight contribute to the average.
+    pub fn average_mark(&self) -> Option<u32> {
+        let (sum, weights) = self
+            .iter()
+            .filter_map(|a| Some((a.mark()? * a.weight()?, a.weight()?)))
+            .fold((0, 0), |(sum, weights), (m, w)| (sum + m, weights + w));
+
+        if weights == 0 {
+            return None;
+        }
+
+        Some(sum / weights)
+    }
+
     /// Returns the length of the collection.
     pub fn len(&self) -> usize {
         self.inner.len()
     }
 
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Appends an element to the back of the collection.
     ///
     /// See [VecDeque::push_back].
     ///
     /// # Errors
     /// An assignment with the same name already exists.
-    /// The sum of all assignment weights is out of bounds (`>100`).
+    /// The sum of all assignment weights, including the new one, is out of bounds (`>100`).
     pub fn push_back(&mut self, assignment: Assignment) -> Result<(), AssignmentsError> {
         self.can_add_assignment(&assignment)?;
         self.inner.push_back(assignment);
@@ -59,7 +165,7 @@ impl Assignments {
     ///
     /// # Errors
     /// An assignment with the same name already exists.
-    /// The sum of all assignment weights is out of bounds (`>100`).
+    /// The sum of all assignment weights, including the new one, is out of bounds (`>100`).
     pub fn push_front(&mut self, assignment: Assignment) -> Result<(), AssignmentsError> {
         self.can_add_assignment(&assignment)?;
         self.inner.push_front(assignment);
@@ -87,6 +193,24 @@ impl Assignments {
         self.inner.remove(index)
     }
 
+    /// Moves the [Assignment] at the given index one place towards the front of the collection.
+    ///
+    /// Does nothing if the element is already at the front or the index is out of bounds.
+    pub fn move_up(&mut self, index: usize) {
+        if index > 0 && index < self.inner.len() {
+            self.inner.swap(index - 1, index);
+        }
+    }
+
+    /// Moves the [Assignment] at the given index one place towards the back of the collection.
+    ///
+    /// Does nothing if the element is already at the back or the index is out of bounds.
+    pub fn move_down(&mut self, index: usize) {
+        if index < self.inner.len().saturating_sub(1) {
+            self.inner.swap(index, index + 1);
+        }
+    }
+
     /// Moves all the [Assignment]s of `other` into `self`, leaving `other` empty.
     ///
     /// See [VecDeque::append].
@@ -96,11 +220,11 @@ impl Assignments {
 
     /// Check if the assignment is allowed to be added to the collection.
     fn can_add_assignment(&self, assignment: &Assignment) -> Result<(), AssignmentsError> {
-        if self.inner.iter().any(|a| a.name() == assignment.name()) {
+        if self.contains(assignment.name()) {
             return Err(AssignmentsError::NonUniqueName(assignment.name().to_owned()));
         }
 
-        let sum = self.inner.iter().filter_map(|a| a.weight()).sum::<u32>();
+        let sum = self.total_weight() + assignment.weight().unwrap_or(0);
         if sum > MAX_SUM_WEIGHT {
             return Err(AssignmentsError::WeightsOutOfBounds(sum));
         }
@@ -109,6 +233,26 @@ impl Assignments {
     }
 }
 
+/// Adaptors for iterators over [Assignment]s.
+pub trait AssignmentIterExt<'a>: Iterator<Item = &'a Assignment> + Sized {
+    /// Filters to the [Assignment]s that have a mark.
+    fn marked(self) -> impl Iterator<Item = &'a Assignment> {
+        self.filter(|a| a.mark().is_some())
+    }
+
+    /// Filters to the [Assignment]s that have been completed, whether or not they are marked.
+    fn completed(self) -> impl Iterator<Item = &'a Assignment> {
+        self.filter(|a| a.status() != Status::Incomplete)
+    }
+
+    /// Filters to the [Assignment]s with the given [Status].
+    fn in_status(self, status: Status) -> impl Iterator<Item = &'a Assignment> {
+        self.filter(move |a| a.status() == status)
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a Assignment>> AssignmentIterExt<'a> for I {}
+
 impl IntoIterator for Assignments {
     type Item = Assignment;
 
@@ -119,12 +263,35 @@ impl IntoIterator for Assignments {
     }
 }
 
+impl<'a> IntoIterator for &'a Assignments {
+    type Item = &'a Assignment;
+
+    type IntoIter = std::collections::vec_deque::Iter<'a, Assignment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Assignments {
+    type Item = &'a mut Assignment;
+
+    type IntoIter = std::collections::vec_deque::IterMut<'a, Assignment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
 impl<'a> Extend<&'a Assignment> for Assignments {
     fn extend<T: IntoIterator<Item = &'a Assignment>>(&mut self, iter: T) {
         self.inner.extend(iter.into_iter().cloned())
     }
 }
 
+/// Unchecked conversion from an array of [Assignment]s.
+///
+/// Does *not* validate the collection, see [Assignments::try_from_iter] for a checked conversion.
 impl<const N: usize> From<[Assignment; N]> for Assignments {
     fn from(assignments: [Assignment; N]) -> Self {
         Self {
@@ -133,6 +300,9 @@ impl<const N: usize> From<[Assignment; N]> for Assignments {
     }
 }
 
+/// Unchecked conversion from a [Vec] of [Assignment]s.
+///
+/// Does *not* validate the collection, see [Assignments::try_from_iter] for a checked conversion.
 impl From<Vec<Assignment>> for Assignments {
     fn from(assignments: Vec<Assignment>) -> Self {
         Self {
@@ -140,3 +310,168 @@ impl From<Vec<Assignment>> for Assignments {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted(name: &str, weight: u32) -> Assignment {
+        let mut a = Assignment::new(name).unwrap();
+        a.set_weight(weight).unwrap();
+        a
+    }
+
+    #[test]
+    fn iter_sums_weights() {
+        let assignments = Assignments::from([weighted("A", 20), weighted("B", 30)]);
+        let sum: u32 = assignments.iter().filter_map(|a| a.weight()).sum();
+        assert_eq!(sum, 50);
+        assert_eq!(assignments.iter().len(), 2);
+        assert_eq!(assignments.iter().next_back().unwrap().name(), "B");
+    }
+
+    #[test]
+    fn iter_mut_modifies_in_place() {
+        let mut assignments = Assignments::from([weighted("A", 20), weighted("B", 30)]);
+        for a in &mut assignments {
+            a.set_mark(50).unwrap();
+        }
+        assert!((&assignments).into_iter().all(|a| a.mark() == Some(50)));
+    }
+
+    #[test]
+    fn try_from_iter_accepts_valid_assignments() {
+        let assignments =
+            Assignments::try_from_iter([weighted("A", 50), weighted("B", 50)]).unwrap();
+        assert_eq!(assignments.len(), 2);
+    }
+
+    #[test]
+    fn try_from_iter_rejects_duplicate_names() {
+        let result = Assignments::try_from_iter([weighted("A", 20), weighted("A", 30)]);
+        assert!(matches!(result, Err(AssignmentsError::NonUniqueName(name)) if name == "A"));
+    }
+
+    #[test]
+    fn contains_and_position_present() {
+        let assignments = Assignments::from([weighted("A", 20), weighted("B", 30)]);
+        assert!(assignments.contains("B"));
+        assert_eq!(assignments.position("B"), Some(1));
+    }
+
+    #[test]
+    fn contains_and_position_absent() {
+        let assignments = Assignments::from([weighted("A", 20)]);
+        assert!(!assignments.contains("C"));
+        assert_eq!(assignments.position("C"), None);
+    }
+
+    #[test]
+    fn completed_and_pending() {
+        let mut marked = weighted("Marked", 20);
+        marked.set_mark(50).unwrap();
+        let mut complete = weighted("Complete", 20);
+        complete.set_status(Status::Complete).unwrap();
+        let incomplete = weighted("Incomplete", 20);
+        let assignments = Assignments::from([marked, complete, incomplete]);
+
+        let completed: Vec<_> = assignments.completed().iter().map(|a| a.name()).collect();
+        assert_eq!(completed, ["Marked", "Complete"]);
+        let pending: Vec<_> = assignments.pending().iter().map(|a| a.name()).collect();
+        assert_eq!(pending, ["Incomplete"]);
+    }
+
+    #[test]
+    fn average_mark_is_weighted() {
+        let mut a = weighted("A", 20);
+        a.set_mark(100).unwrap();
+        let mut b = weighted("B", 30);
+        b.set_mark(50).unwrap();
+        let assignments = Assignments::from([a, b, weighted("C", 50)]);
+        // (100 * 20 + 50 * 30) / (20 + 30)
+        assert_eq!(assignments.average_mark(), Some(70));
+    }
+
+    #[test]
+    fn average_mark_without_marks_is_none() {
+        let assignments = Assignments::from([weighted("A", 20)]);
+        assert_eq!(assignments.average_mark(), None);
+        assert_eq!(Assignments::new().average_mark(), None);
+    }
+
+    #[test]
+    fn iter_ext_adaptors() {
+        let mut marked = weighted("Marked", 20);
+        marked.set_mark(50).unwrap();
+        let mut provisional = weighted("Provisional", 20);
+        provisional.set_status(Status::Submitted).unwrap();
+        provisional.set_mark_keeping_status(40).unwrap();
+        let assignments = Assignments::from([marked, provisional, weighted("Incomplete", 20)]);
+
+        let names: Vec<_> = assignments.iter().marked().map(|a| a.name()).collect();
+        assert_eq!(names, ["Marked", "Provisional"]);
+        let names: Vec<_> = assignments
+            .iter()
+            .in_status(Status::Submitted)
+            .map(|a| a.name())
+            .collect();
+        assert_eq!(names, ["Provisional"]);
+        assert_eq!(assignments.iter().in_status(Status::Complete).count(), 0);
+        let names: Vec<_> = assignments.iter().completed().map(|a| a.name()).collect();
+        assert_eq!(names, ["Marked", "Provisional"]);
+    }
+
+    #[test]
+    fn push_back_counts_incoming_weight() {
+        let mut assignments = Assignments::from([weighted("A", 60)]);
+        assert!(matches!(
+            assignments.push_back(weighted("B", 41)),
+            Err(AssignmentsError::WeightsOutOfBounds(101))
+        ));
+        assert!(matches!(
+            assignments.push_front(weighted("B", 41)),
+            Err(AssignmentsError::WeightsOutOfBounds(101))
+        ));
+        assert_eq!(assignments.len(), 1);
+        assignments.push_back(weighted("B", 40)).unwrap();
+    }
+
+    fn names(assignments: &Assignments) -> Vec<&str> {
+        assignments.iter().map(|a| a.name()).collect()
+    }
+
+    #[test]
+    fn move_first_up_is_noop() {
+        let mut assignments = Assignments::from([weighted("A", 0), weighted("B", 0)]);
+        assignments.move_up(0);
+        assert_eq!(names(&assignments), ["A", "B"]);
+    }
+
+    #[test]
+    fn move_middle_up_and_down() {
+        let mut assignments =
+            Assignments::from([weighted("A", 0), weighted("B", 0), weighted("C", 0)]);
+        assignments.move_up(1);
+        assert_eq!(names(&assignments), ["B", "A", "C"]);
+        assignments.move_down(1);
+        assert_eq!(names(&assignments), ["B", "C", "A"]);
+    }
+
+    #[test]
+    fn move_last_down_is_noop() {
+        let mut assignments = Assignments::from([weighted("A", 0), weighted("B", 0)]);
+        assignments.move_down(1);
+        assert_eq!(names(&assignments), ["A", "B"]);
+    }
+
+    #[test]
+    fn move_out_of_bounds_is_noop() {
+        let mut assignments = Assignments::from([weighted("A", 0), weighted("B", 0)]);
+        assignments.move_up(5);
+        assignments.move_down(5);
+        assignments.move_down(usize::MAX);
+        assignments.move_up(usize::MAX);
+        assert_eq!(names(&assignments), ["A", "B"]);
+        Assignments::new().move_down(0);
+    }
+}
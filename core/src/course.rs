@@ -1,10 +1,36 @@
-use crate::Assignments;
+use crate::assignments::MAX_SUM_WEIGHT;
+use crate::{Assignment, AssignmentIterExt, Assignments, AssignmentsError, Status};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Representation of a [Course].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// [PartialEq] compares every field, see [Course::same_course] to compare by name only.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Course {
     pub name: String,
     pub assignments: Assignments,
+    /// Credits used to weight the [Course] in [gpa], always positive and finite.
+    credits: f32,
+}
+
+/// Errors from invalid [Course] data.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CourseError {
+    #[error("Credits must be positive and finite (found: `{0}`)")]
+    InvalidCredits(f32),
+}
+
+/// Summary of the grade for a [Course].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CourseGradeSummary {
+    /// Percentage of the final grade earned so far.
+    pub current: u32,
+    /// Final grade if the remaining assignments are marked at the current average.
+    pub projected: Option<u32>,
+    /// Weight of the final grade that is yet to be marked.
+    pub remaining_weight: u32,
 }
 
 impl Course {
@@ -23,6 +49,114 @@ impl Course {
             ..Default::default()
         }
     }
+
+    /// Rename the [Course].
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.to_owned();
+    }
+
+    /// Returns the credits used to weight the [Course] in [gpa].
+    pub fn credits(&self) -> f32 {
+        self.credits
+    }
+
+    /// Set the credits used to weight the [Course] in [gpa].
+    ///
+    /// # Errors
+    /// - `credits` is not positive and finite.
+    pub fn set_credits(&mut self, credits: f32) -> Result<(), CourseError> {
+        validate_credits(credits)?;
+        self.credits = credits;
+        Ok(())
+    }
+
+    /// Checks that the [Course]'s own fields are valid.
+    ///
+    /// Useful after deserialising, which bypasses the validation done by the setters.
+    /// The assignments are not checked.
+    ///
+    /// # Errors
+    /// - `credits` is not positive and finite.
+    pub fn is_valid(&self) -> Result<(), CourseError> {
+        validate_credits(self.credits)
+    }
+
+    /// Returns `true` if both [Course]s have the same name, ignoring all other fields.
+    pub fn same_course(&self, other: &Course) -> bool {
+        self.name == other.name
+    }
+
+    /// Moves all assignments of `other` into `self`.
+    ///
+    /// Either every assignment is added, or `self` is left unchanged.
+    ///
+    /// # Errors
+    /// An assignment with the same name already exists.
+    /// The sum of all assignment weights is out of bounds (`>100`).
+    pub fn merge(&mut self, other: Course) -> Result<(), AssignmentsError> {
+        let mut assignments = self.assignments.clone();
+        for assignment in other.assignments {
+            assignments.push_back(assignment)?;
+        }
+        self.assignments = assignments;
+        Ok(())
+    }
+
+    /// Returns the number of assignments in the [Course].
+    pub fn assignment_count(&self) -> usize {
+        self.assignments.len()
+    }
+
+    /// Returns `true` if the [Course] has no assignments.
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    /// Returns the assignments in the [Course] with the given [Status].
+    pub fn assignments_by_status(&self, status: Status) -> Vec<&Assignment> {
+        self.assignments.iter().in_status(status).collect()
+    }
+
+    /// Returns the sum of the weights of all assignments in the [Course].
+    pub fn total_weight(&self) -> u32 {
+        self.assignments.total_weight()
+    }
+
+    /// Returns `true` if the weights of all assignments sum to exactly 100.
+    pub fn weight_is_complete(&self) -> bool {
+        self.total_weight() == MAX_SUM_WEIGHT
+    }
+
+    /// Returns the signed difference between the total weight and 100.
+    ///
+    /// Negative when under-weighted, positive when over-weighted.
+    pub fn weight_discrepancy(&self) -> i32 {
+        self.total_weight() as i32 - MAX_SUM_WEIGHT as i32
+    }
+
+    /// Returns the current and projected grades for the [Course], along with the weight
+    /// that is yet to be marked.
+    pub fn grade_summary(&self) -> CourseGradeSummary {
+        let marked_weight: u32 = self
+            .assignments
+            .iter()
+            .marked()
+            .filter_map(|a| a.weight())
+            .sum();
+
+        CourseGradeSummary {
+            current: self.assignments.iter().filter_map(|a| a.percentage()).sum(),
+            projected: self.average_mark(),
+            remaining_weight: MAX_SUM_WEIGHT.saturating_sub(marked_weight),
+        }
+    }
+
+    /// Returns the average mark of the [Course], weighted by each assignment's weight.
+    ///
+    /// See [Assignments::average_mark].
+    pub fn average_mark(&self) -> Option<u32> {
+        self.assignments.average_mark()
+    }
 }
 
 impl Default for Course {
@@ -30,6 +164,291 @@ impl Default for Course {
         Self {
             name: String::from("Unknown course"),
             assignments: Default::default(),
+            credits: 1.0,
+        }
+    }
+}
+
+/// Check that `credits` is positive and finite.
+fn validate_credits(credits: f32) -> Result<(), CourseError> {
+    if !credits.is_finite() || credits <= 0.0 {
+        return Err(CourseError::InvalidCredits(credits));
+    }
+    Ok(())
+}
+
+/// Calculates the grade point average over the given [Course]s, weighted by credits.
+///
+/// `scale` converts a course's average mark (as a percentage) into grade points.
+/// Courses without an average mark are not included.
+///
+/// Returns `Ok(None)` if no course has an average mark.
+///
+/// # Errors
+/// - Any course has credits that are not positive and finite, which is only possible for
+///   deserialised courses that have not been checked with [Course::is_valid].
+pub fn gpa(courses: &[Course], scale: impl Fn(u32) -> f32) -> Result<Option<f32>, CourseError> {
+    for course in courses {
+        course.is_valid()?;
+    }
+
+    let (points, credits) = courses
+        .iter()
+        .filter_map(|c| Some((scale(c.average_mark()?) * c.credits, c.credits)))
+        .fold((0.0, 0.0), |(points, credits), (p, c)| (points + p, credits + c));
+
+    if credits == 0.0 {
+        return Ok(None);
+    }
+
+    Ok(Some(points / credits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(name: &str, mark: u32, credits: f32) -> Course {
+        let a = Assignment::builder()
+            .name("Exam")
+            .mark(mark)
+            .weight(100)
+            .build()
+            .unwrap();
+        let mut course = Course::with_assignments(name, Assignments::from([a]));
+        course.credits = credits;
+        course
+    }
+
+    #[test]
+    fn gpa_is_weighted_by_credits() {
+        let courses = [course("A", 90, 3.0), course("B", 50, 1.0)];
+        let gpa = gpa(&courses, |pct| pct as f32 / 10.0).unwrap().unwrap();
+        assert!((gpa - 8.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn gpa_without_marks_is_none() {
+        assert_eq!(gpa(&[Course::new("A")], |pct| pct as f32).unwrap(), None);
+        assert_eq!(gpa(&[], |pct| pct as f32).unwrap(), None);
+    }
+
+    #[test]
+    fn gpa_rejects_invalid_credits() {
+        for credits in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let courses = [course("A", 90, 3.0), course("B", 50, credits)];
+            assert!(matches!(
+                gpa(&courses, |pct| pct as f32),
+                Err(CourseError::InvalidCredits(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn gpa_rejects_invalid_credits_without_marks() {
+        let mut unmarked = Course::new("B");
+        unmarked.credits = 0.0;
+        let courses = [course("A", 90, 3.0), unmarked];
+        assert!(gpa(&courses, |pct| pct as f32).is_err());
+    }
+
+    #[test]
+    fn set_credits_rejects_invalid_credits() {
+        let mut course = Course::new("A");
+        assert_eq!(course.credits(), 1.0);
+        for credits in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                course.set_credits(credits),
+                Err(CourseError::InvalidCredits(_))
+            ));
+            assert_eq!(course.credits(), 1.0);
+        }
+        course.set_credits(2.5).unwrap();
+        assert_eq!(course.credits(), 2.5);
+    }
+
+    #[test]
+    fn is_valid_rejects_deserialised_invalid_credits() {
+        let json = r#"{"name":"A","assignments":{"inner":[]},"credits":-2.0}"#;
+        let course: Course = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            course.is_valid(),
+            Err(CourseError::InvalidCredits(_))
+        ));
+        assert!(Course::new("A").is_valid().is_ok());
+    }
+
+    #[test]
+    fn empty_course() {
+        let course = Course::new("A");
+        assert!(course.is_empty());
+        assert_eq!(course.assignment_count(), 0);
+    }
+
+    #[test]
+    fn populated_course() {
+        let assignments = Assignments::from([
+            Assignment::new("A").unwrap(),
+            Assignment::new("B").unwrap(),
+        ]);
+        let course = Course::with_assignments("A", assignments);
+        assert!(!course.is_empty());
+        assert_eq!(course.assignment_count(), 2);
+    }
+
+    #[test]
+    fn rename() {
+        let mut course = Course::new("A");
+        course.rename("B");
+        assert_eq!(course.name, "B");
+    }
+
+    #[test]
+    fn same_course_compares_names_only() {
+        let a = Course::new("A");
+        let assignments = Assignments::from([Assignment::new("X").unwrap()]);
+        let mut b = Course::with_assignments("A", assignments);
+        b.set_credits(2.0).unwrap();
+        assert!(a.same_course(&b));
+        assert_ne!(a, b);
+        assert!(!a.same_course(&Course::new("B")));
+    }
+
+    fn weighted(name: &str, weight: u32) -> Assignment {
+        Assignment::builder().name(name).weight(weight).build().unwrap()
+    }
+
+    #[test]
+    fn merge_absorbs_assignments() {
+        let mut course = Course::with_assignments("A", Assignments::from([weighted("X", 50)]));
+        let other = Course::with_assignments("B", Assignments::from([weighted("Y", 50)]));
+        course.merge(other).unwrap();
+        assert_eq!(course.assignment_count(), 2);
+        assert!(course.assignments.contains("Y"));
+    }
+
+    #[test]
+    fn merge_name_collision_leaves_course_unchanged() {
+        let mut course = Course::with_assignments("A", Assignments::from([weighted("X", 50)]));
+        let before = course.clone();
+        let other = Course::with_assignments(
+            "B",
+            Assignments::from([weighted("Y", 10), weighted("X", 10)]),
+        );
+        assert!(matches!(
+            course.merge(other),
+            Err(AssignmentsError::NonUniqueName(_))
+        ));
+        assert_eq!(course, before);
+    }
+
+    #[test]
+    fn merge_weight_overflow_leaves_course_unchanged() {
+        let mut course = Course::with_assignments("A", Assignments::from([weighted("X", 50)]));
+        let before = course.clone();
+        let other = Course::with_assignments(
+            "B",
+            Assignments::from([weighted("Y", 40), weighted("Z", 20)]),
+        );
+        assert!(matches!(
+            course.merge(other),
+            Err(AssignmentsError::WeightsOutOfBounds(110))
+        ));
+        assert_eq!(course, before);
+    }
+
+    #[test]
+    fn under_weighted_course() {
+        let course = Course::with_assignments("A", Assignments::from([weighted("X", 40)]));
+        assert!(!course.weight_is_complete());
+        assert_eq!(course.weight_discrepancy(), -60);
+    }
+
+    #[test]
+    fn exactly_weighted_course() {
+        let assignments = Assignments::from([weighted("X", 40), weighted("Y", 60)]);
+        let course = Course::with_assignments("A", assignments);
+        assert_eq!(course.total_weight(), 100);
+        assert!(course.weight_is_complete());
+        assert_eq!(course.weight_discrepancy(), 0);
+    }
+
+    #[test]
+    fn over_weighted_course() {
+        // `From` is unchecked, so can exceed the bound
+        let assignments = Assignments::from([weighted("X", 60), weighted("Y", 60)]);
+        let course = Course::with_assignments("A", assignments);
+        assert!(!course.weight_is_complete());
+        assert_eq!(course.weight_discrepancy(), 20);
+    }
+
+    fn with_status(name: &str, status: Status) -> Assignment {
+        let mut a = Assignment::new(name).unwrap();
+        if status == Status::Marked {
+            a.set_mark(50).unwrap();
+        } else {
+            a.set_status(status).unwrap();
         }
+        a
+    }
+
+    #[test]
+    fn assignments_by_status_filters_mixed_statuses() {
+        let course = Course::with_assignments(
+            "A",
+            Assignments::from([
+                with_status("W", Status::Incomplete),
+                with_status("X", Status::Submitted),
+                with_status("Y", Status::Marked),
+                with_status("Z", Status::Submitted),
+            ]),
+        );
+        let names = |status| {
+            course
+                .assignments_by_status(status)
+                .into_iter()
+                .map(Assignment::name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Status::Incomplete), ["W"]);
+        assert_eq!(names(Status::Submitted), ["X", "Z"]);
+        assert_eq!(names(Status::Marked), ["Y"]);
+        assert!(names(Status::Late).is_empty());
+    }
+
+    #[test]
+    fn assignments_by_status_includes_provisional_marks() {
+        let mut a = with_status("X", Status::Late);
+        a.set_mark_keeping_status(70).unwrap();
+        let course = Course::with_assignments("A", Assignments::from([a]));
+        assert_eq!(course.assignments_by_status(Status::Late).len(), 1);
+        assert!(course.assignments_by_status(Status::Marked).is_empty());
+    }
+
+    #[test]
+    fn grade_summary_half_marked() {
+        let mut a = weighted("A", 50);
+        a.set_mark(80).unwrap();
+        let course = Course::with_assignments("C", Assignments::from([a, weighted("B", 50)]));
+        assert_eq!(
+            course.grade_summary(),
+            CourseGradeSummary {
+                current: 40,
+                projected: Some(80),
+                remaining_weight: 50,
+            }
+        );
+    }
+
+    #[test]
+    fn grade_summary_empty_course() {
+        assert_eq!(
+            Course::new("C").grade_summary(),
+            CourseGradeSummary {
+                current: 0,
+                projected: None,
+                remaining_weight: 100,
+            }
+        );
     }
 }
@@ -1,7 +1,9 @@
 mod assignment;
 mod assignments;
 mod course;
+mod status;
 
-pub use assignment::Assignment;
-pub use assignments::Assignments;
-pub use course::Course;
+pub use assignment::{format_percent, Assignment, AssignmentError};
+pub use assignments::{AssignmentIterExt, Assignments, AssignmentsError};
+pub use course::{gpa, Course, CourseError, CourseGradeSummary};
+pub use status::Status;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Completion status of an [Assignment](crate::Assignment).
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub enum Status {
+    /// The assignment has not been completed.
+    #[default]
+    Incomplete,
+    /// The assignment has been completed but not yet marked.
+    Complete,
+    /// The assignment has been submitted but not yet marked.
+    Submitted,
+    /// The assignment has been submitted after the deadline but not yet marked.
+    Late,
+    /// The assignment has been marked.
+    ///
+    /// Only valid when the assignment has a mark.
+    Marked,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip() {
+        for status in [
+            Status::Incomplete,
+            Status::Complete,
+            Status::Submitted,
+            Status::Late,
+            Status::Marked,
+        ] {
+            let json = serde_json::to_string(&status).unwrap();
+            assert_eq!(serde_json::from_str::<Status>(&json).unwrap(), status);
+        }
+    }
+
+    #[test]
+    fn serialises_as_variant_name() {
+        assert_eq!(
+            serde_json::to_string(&Status::Submitted).unwrap(),
+            r#""Submitted""#
+        );
+        assert_eq!(serde_json::to_string(&Status::Late).unwrap(), r#""Late""#);
+    }
+}